@@ -70,7 +70,51 @@ use std::process;
 /// additional source files or compiler flags, and lastly call its [`compile`]
 /// method to execute the C++ build.
 ///
+/// The returned Build comes with two include directories already set up, in
+/// this order: `target/cxxbridge`, which holds the `rust/cxx.h` header and the
+/// generated bridge headers, and the directory containing `target`, so that
+/// project headers resolve by the same path that appears in `include!` within
+/// the bridge. Any directories added afterward with [`include`] are searched
+/// after these two. Both are located from `OUT_DIR`, so outside of a Cargo
+/// build script, or if no `target` directory can be found above `OUT_DIR`,
+/// this function reports an error and exits instead of returning a Build that
+/// lacks them.
+///
+/// Generated code goes to fixed locations derived from the path of the Rust
+/// source file relative to the directory containing `target`, or to the
+/// nearest ancestor of that directory which contains the source file. For a
+/// bridge in `path/to/crate/src/main.rs`, the implementation is written to
+/// `$OUT_DIR/path/to/crate/src/main.rs.cc` and the header to
+/// `$OUT_DIR/path/to/crate/src/main.rs.h`. The header is additionally linked,
+/// or copied where symlinks are unavailable, at both
+/// `target/cxxbridge/path/to/crate/src/main.rs` and
+/// `target/cxxbridge/path/to/crate/src/main.rs.h`. None of these names depend
+/// on the contents of the source file, so they stay the same across rebuilds
+/// for as long as its path and `OUT_DIR` do.
+///
+/// The C++ compiler is selected by cc. A compiler set with [`compiler`] on the
+/// returned Build is used as is, regardless of the environment. Otherwise cc
+/// reads `CXX_<target>`, `HOST_CXX` or `TARGET_CXX`, and `CXX`, in that order
+/// of preference, before falling back to the platform's default compiler.
+///
+/// A compiler cache such as sccache or ccache is applied by naming it in that
+/// same variable, as in `CXX="ccache c++"`. When `CXX` names no wrapper, recent
+/// versions of cc also honor `RUSTC_WRAPPER` if it is sccache, cachepot,
+/// buildcache or kache; `RUSTC_WRAPPER=ccache` has no effect on the C++ side.
+/// Neither applies when a compiler was set explicitly with [`compiler`]. The
+/// generated bridge sources are compiled by the same Build as your own, so they
+/// are cached the same way.
+///
+/// Position-independent code follows cc's default, which in recent versions of
+/// cc is `-fPIC` on every target except Windows, bare metal (`target_os =
+/// "none"`), UEFI, Vita, and wasm32/wasm64. Call [`pic`]`(true)` on the
+/// returned Build to force it, for example when the resulting static library
+/// gets linked into a shared object on one of those targets.
+///
 /// [`compile`]: https://docs.rs/cc/1.0.49/cc/struct.Build.html#method.compile
+/// [`compiler`]: https://docs.rs/cc/1.0.49/cc/struct.Build.html#method.compiler
+/// [`include`]: https://docs.rs/cc/1.0.49/cc/struct.Build.html#method.include
+/// [`pic`]: https://docs.rs/cc/1.0.49/cc/struct.Build.html#method.pic
 #[must_use]
 pub fn bridge(rust_source_file: impl AsRef<Path>) -> Build {
     bridges(iter::once(rust_source_file))
@@ -37,6 +37,7 @@ fn try_symlink_header(path: &Path, original: &Path) -> Result<()> {
     let mut file_name = dst.file_name().unwrap().to_os_string();
     file_name.push(".h");
     let ref dst2 = dst.with_file_name(file_name);
+    let _ = fs::remove_file(dst2);
     symlink_or_copy(path, dst2)?;
 
     Ok(())
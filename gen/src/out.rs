@@ -58,16 +58,39 @@ impl OutFile {
         let front = &self.front.bytes;
         let content = &self.content.borrow().bytes;
         let len = front.len() + !front.is_empty() as usize + content.len();
-        let mut out = String::with_capacity(len);
-        out.push_str(front);
+        let mut body = String::with_capacity(len);
+        body.push_str(front);
         if !front.is_empty() {
-            out.push('\n');
+            body.push('\n');
         }
-        out.push_str(content);
+        body.push_str(content);
+        if !self.header {
+            return body.into_bytes();
+        }
+
+        // Fallback for compilers without #pragma once. The guard is derived
+        // from the generated code so that distinct bridges sharing a namespace
+        // don't shadow one another.
+        let guard = format!("CXXBRIDGE03_HEADER_{:016x}", fnv1a(body.as_bytes()));
+        let mut out = String::new();
+        out.push_str("#pragma once\n");
+        writeln!(out, "#ifndef {}", guard).unwrap();
+        writeln!(out, "#define {}", guard).unwrap();
+        out.push_str(&body);
+        writeln!(out, "#endif // {}", guard).unwrap();
         out.into_bytes()
     }
 }
 
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
 impl Write for Content {
     fn write_str(&mut self, s: &str) -> fmt::Result {
         self.write(s);
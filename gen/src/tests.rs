@@ -1,4 +1,7 @@
-use crate::gen::{generate_from_string, Opt};
+use crate::gen::{generate_from_path, generate_from_string, Opt};
+use std::env;
+use std::fs;
+use std::process;
 
 const CPP_EXAMPLE: &str = r#"
     #[cxx::bridge]
@@ -9,6 +12,19 @@ const CPP_EXAMPLE: &str = r#"
     }
 "#;
 
+const SHARED_EXAMPLE: &str = r#"
+    #[cxx::bridge(namespace = example)]
+    mod ffi {
+        struct Shared {
+            z: usize,
+        }
+        extern "C" {
+            type C;
+            fn make_c() -> UniquePtr<C>;
+        }
+    }
+"#;
+
 #[test]
 fn test_cpp() {
     let opts = Opt {
@@ -36,3 +52,71 @@ fn test_annotation() {
     let output = std::str::from_utf8(&output.implementation).unwrap();
     assert!(output.contains("ANNOTATION void cxxbridge03$do_cpp_thing(::rust::Str::Repr foo)"));
 }
+
+#[test]
+fn test_header_guards() {
+    let opts = Opt {
+        include: Vec::new(),
+        cxx_impl_annotations: None,
+        gen_header: true,
+        gen_implementation: true,
+    };
+    let output = generate_from_string(SHARED_EXAMPLE, &opts).unwrap();
+    let header = std::str::from_utf8(&output.header).unwrap();
+    let implementation = std::str::from_utf8(&output.implementation).unwrap();
+    // The header carries #pragma once, a whole-file guard and per-definition
+    // guards, while shim definitions go only into the implementation file.
+    assert!(header.starts_with("#pragma once\n#ifndef CXXBRIDGE03_HEADER_"));
+    let guard = header_guard(header);
+    assert!(header.ends_with(&format!("#endif // {}\n", guard)));
+    assert!(header.contains("#ifndef CXXBRIDGE03_STRUCT_example$Shared\n"));
+    assert!(header.contains("#endif // CXXBRIDGE03_STRUCT_example$Shared\n"));
+    assert!(!header.contains("cxxbridge03$unique_ptr$example$C$"));
+    assert!(implementation.contains("cxxbridge03$unique_ptr$example$C$"));
+}
+
+#[test]
+fn test_distinct_header_guards() {
+    let opts = Opt {
+        include: Vec::new(),
+        cxx_impl_annotations: None,
+        gen_header: true,
+        gen_implementation: false,
+    };
+    let other = SHARED_EXAMPLE.replace("Shared", "Other");
+    let first = generate_from_string(SHARED_EXAMPLE, &opts).unwrap();
+    let second = generate_from_string(&other, &opts).unwrap();
+    let first = std::str::from_utf8(&first.header).unwrap();
+    let second = std::str::from_utf8(&second.header).unwrap();
+    // Two bridges in the same namespace must not suppress each other when
+    // included into one translation unit.
+    assert_ne!(header_guard(first), header_guard(second));
+}
+
+fn header_guard(header: &str) -> &str {
+    let line = header.lines().nth(1).unwrap();
+    line.trim_start_matches("#ifndef ")
+}
+
+#[test]
+fn test_deterministic() {
+    let opts = Opt {
+        include: Vec::new(),
+        cxx_impl_annotations: None,
+        gen_header: true,
+        gen_implementation: true,
+    };
+    let dir = env::temp_dir().join(format!("cxxbridge-test-{}", process::id()));
+    let first = dir.join("first").join("lib.rs");
+    let second = dir.join("second").join("nested").join("lib.rs");
+    for path in &[&first, &second] {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, SHARED_EXAMPLE).unwrap();
+    }
+    let first = generate_from_path(&first, &opts);
+    let second = generate_from_path(&second, &opts);
+    let _ = fs::remove_dir_all(&dir);
+    // Generated code must not depend on where the source file lives.
+    assert_eq!(first.header, second.header);
+    assert_eq!(first.implementation, second.implementation);
+}
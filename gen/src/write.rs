@@ -17,10 +17,6 @@ pub(super) fn gen(
     let mut out_file = OutFile::new(namespace.clone(), header);
     let out = &mut out_file;
 
-    if header {
-        writeln!(out.front, "#pragma once");
-    }
-
     out.include.extend(opt.include.clone());
     for api in apis {
         if let Api::Include(include) = api {
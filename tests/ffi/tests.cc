@@ -1,5 +1,6 @@
 #include "tests/ffi/tests.h"
 #include "tests/ffi/lib.rs.h"
+#include "tests/ffi/lib.rs.h" // skipped via #pragma once
 #include <cstring>
 #include <iterator>
 #include <numeric>
@@ -0,0 +1,13 @@
+use cxx::{CxxString, UniquePtr};
+
+fn main() {
+    let unique_ptr = UniquePtr::<CxxString>::null();
+    let string = unique_ptr.as_ref();
+    drop(unique_ptr);
+    assert!(string.is_none());
+
+    let unique_ptr = UniquePtr::<CxxString>::null();
+    let string = unique_ptr.as_ref();
+    let _ = unique_ptr.into_raw();
+    assert!(string.is_none());
+}